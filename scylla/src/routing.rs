@@ -0,0 +1,83 @@
+//! Token-based routing primitives shared by the load balancing and connection-pool layers.
+
+/// A token determines which nodes are replicas for a partition key and, together with a node's
+/// [`Sharder`], which shard of that node owns the corresponding data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Token {
+    pub value: i64,
+}
+
+/// The index of a shard within a ScyllaDB node's shard-per-core architecture.
+pub type Shard = u32;
+
+/// Computes which shard of a ScyllaDB node owns a given token.
+///
+/// This mirrors the sharding function used by ScyllaDB itself: the token is reinterpreted as an
+/// unsigned, bias-corrected 64-bit value, the `shard_msb_ignore` highest bits are shifted out,
+/// and the remaining bits are scaled into `[0, nr_shards)`. Cassandra nodes don't shard their
+/// data this way, so they have no `Sharder`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sharder {
+    nr_shards: u32,
+    shard_msb_ignore: u8,
+}
+
+impl Sharder {
+    pub fn new(nr_shards: u32, shard_msb_ignore: u8) -> Self {
+        Self {
+            nr_shards,
+            shard_msb_ignore,
+        }
+    }
+
+    /// Returns the shard that owns `token` on this node, or `None` if the node reports zero
+    /// shards (which would make the scaling below meaningless).
+    pub fn shard_of(&self, token: Token) -> Option<Shard> {
+        if self.nr_shards == 0 {
+            return None;
+        }
+
+        let biased_token = (token.value as u64).wrapping_add(1u64 << 63);
+        let biased_token = biased_token << self.shard_msb_ignore;
+        Some(((biased_token as u128 * self.nr_shards as u128) >> 64) as Shard)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shard_of_stays_within_range() {
+        let sharder = Sharder::new(4, 12);
+        for value in [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX] {
+            let shard = sharder.shard_of(Token { value }).unwrap();
+            assert!(shard < 4, "shard {shard} out of range for nr_shards=4");
+        }
+    }
+
+    #[test]
+    fn shard_of_is_deterministic() {
+        let sharder = Sharder::new(8, 12);
+        let token = Token { value: 123_456_789 };
+        assert_eq!(sharder.shard_of(token), sharder.shard_of(token));
+    }
+
+    #[test]
+    fn shard_of_spreads_across_shards() {
+        let sharder = Sharder::new(16, 12);
+        // The shard is derived from the *high* bits of the token once the top `shard_msb_ignore`
+        // bits are discarded, i.e. roughly bits `63 - shard_msb_ignore - log2(nr_shards)` and up.
+        // For this sharder that's around bit 48, so put the variation there.
+        let shards: std::collections::BTreeSet<_> = (0..16i64)
+            .map(|i| sharder.shard_of(Token { value: i << 48 }).unwrap())
+            .collect();
+        assert_eq!(shards.len(), 16);
+    }
+
+    #[test]
+    fn shard_of_none_with_zero_shards() {
+        let sharder = Sharder::new(0, 12);
+        assert_eq!(sharder.shard_of(Token { value: 42 }), None);
+    }
+}
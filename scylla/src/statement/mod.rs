@@ -6,8 +6,10 @@ use crate::transport::execution_profile::ExecutionProfileHandle;
 pub mod batch;
 pub mod prepared_statement;
 pub mod query;
+pub mod timestamp_generator;
 
 pub use crate::frame::types::{Consistency, SerialConsistency};
+pub use timestamp_generator::{MonotonicTimestampGenerator, TimestampGenerator};
 
 #[derive(Debug)]
 pub struct StatementConfig {
@@ -23,6 +25,11 @@ pub struct StatementConfig {
     pub history_listener: Option<Arc<dyn HistoryListener>>,
 
     pub execution_profile_handle: Option<ExecutionProfileHandle>,
+
+    /// Generator used to stamp outgoing frames when a statement has no explicit `timestamp`
+    /// set. Falls back to the one configured on the statement's execution profile when unset
+    /// here, so a whole group of statements can share a single generator.
+    pub timestamp_generator: Option<Arc<dyn TimestampGenerator>>,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -37,6 +44,7 @@ impl Default for StatementConfig {
             request_timeout: None,
             history_listener: None,
             execution_profile_handle: None,
+            timestamp_generator: None,
         }
     }
 }
@@ -46,6 +54,7 @@ impl Clone for StatementConfig {
         Self {
             history_listener: self.history_listener.clone(),
             execution_profile_handle: self.execution_profile_handle.clone(),
+            timestamp_generator: self.timestamp_generator.clone(),
             ..*self
         }
     }
@@ -57,4 +66,81 @@ impl StatementConfig {
     pub fn determine_consistency(&self, default_consistency: Consistency) -> Consistency {
         self.consistency.unwrap_or(default_consistency)
     }
+
+    /// Resolves the timestamp that the frame-serialization layer should stamp onto the outgoing
+    /// QUERY/EXECUTE/BATCH message.
+    ///
+    /// Precedence is: the statement's explicit `timestamp`, then its own `timestamp_generator`,
+    /// then the one configured on its execution profile. Returns `None` if none of those are
+    /// set, in which case the server assigns the write timestamp itself.
+    #[must_use]
+    pub fn resolve_timestamp(&self) -> Option<i64> {
+        if let Some(timestamp) = self.timestamp {
+            return Some(timestamp);
+        }
+
+        let generator = self.timestamp_generator.clone().or_else(|| {
+            self.execution_profile_handle
+                .as_ref()
+                .and_then(ExecutionProfileHandle::timestamp_generator)
+        })?;
+
+        Some(generator.next_timestamp())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::execution_profile::ExecutionProfile;
+
+    #[derive(Debug)]
+    struct FixedGenerator(i64);
+
+    impl TimestampGenerator for FixedGenerator {
+        fn next_timestamp(&self) -> i64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn resolve_timestamp_prefers_explicit_timestamp() {
+        let config = StatementConfig {
+            timestamp: Some(1),
+            timestamp_generator: Some(Arc::new(FixedGenerator(2))),
+            ..Default::default()
+        };
+        assert_eq!(config.resolve_timestamp(), Some(1));
+    }
+
+    #[test]
+    fn resolve_timestamp_prefers_statement_generator_over_profile() {
+        let profile = ExecutionProfile::builder()
+            .timestamp_generator(Arc::new(FixedGenerator(2)))
+            .build();
+        let config = StatementConfig {
+            timestamp_generator: Some(Arc::new(FixedGenerator(1))),
+            execution_profile_handle: Some(ExecutionProfileHandle::new(profile)),
+            ..Default::default()
+        };
+        assert_eq!(config.resolve_timestamp(), Some(1));
+    }
+
+    #[test]
+    fn resolve_timestamp_falls_back_to_profile_generator() {
+        let profile = ExecutionProfile::builder()
+            .timestamp_generator(Arc::new(FixedGenerator(3)))
+            .build();
+        let config = StatementConfig {
+            execution_profile_handle: Some(ExecutionProfileHandle::new(profile)),
+            ..Default::default()
+        };
+        assert_eq!(config.resolve_timestamp(), Some(3));
+    }
+
+    #[test]
+    fn resolve_timestamp_is_none_without_any_source() {
+        let config = StatementConfig::default();
+        assert_eq!(config.resolve_timestamp(), None);
+    }
 }
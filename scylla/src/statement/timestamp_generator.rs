@@ -0,0 +1,134 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A generator of client-side timestamps, used to stamp outgoing CQL frames when a statement
+/// does not carry an explicit timestamp of its own.
+///
+/// Client-side timestamps are needed for last-write-wins correctness under concurrent writes;
+/// a `TimestampGenerator` lets a whole [`ExecutionProfile`](crate::transport::execution_profile::ExecutionProfile)
+/// share one source of timestamps instead of every caller computing (and trying to keep
+/// monotonic) its own.
+pub trait TimestampGenerator: std::fmt::Debug + Send + Sync {
+    /// Returns the next timestamp to use, in microseconds since the Unix epoch.
+    fn next_timestamp(&self) -> i64;
+}
+
+/// A [`TimestampGenerator`] that returns the current time in microseconds since the Unix epoch,
+/// guaranteeing that successive calls never go backwards (or repeat) even if the system clock
+/// hasn't advanced or has stepped back, by bumping the last returned value by 1µs.
+///
+/// Optionally warns (via the `log` crate) when the generated timestamp drifts ahead of the
+/// system clock by more than a configured threshold, which would indicate that the clock is
+/// lagging badly behind the rate at which timestamps are being requested.
+#[derive(Debug)]
+pub struct MonotonicTimestampGenerator {
+    last: AtomicI64,
+    warn_threshold: Option<Duration>,
+}
+
+impl MonotonicTimestampGenerator {
+    /// Creates a generator with no drift warning.
+    pub fn new() -> Self {
+        Self {
+            last: AtomicI64::new(i64::MIN),
+            warn_threshold: None,
+        }
+    }
+
+    /// Creates a generator that logs a warning whenever the value it returns has drifted ahead
+    /// of the system clock by more than `threshold`.
+    pub fn with_warn_threshold(threshold: Duration) -> Self {
+        Self {
+            last: AtomicI64::new(i64::MIN),
+            warn_threshold: Some(threshold),
+        }
+    }
+
+    fn now_micros() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time is before the Unix epoch")
+            .as_micros() as i64
+    }
+}
+
+impl Default for MonotonicTimestampGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimestampGenerator for MonotonicTimestampGenerator {
+    fn next_timestamp(&self) -> i64 {
+        let now = Self::now_micros();
+
+        let mut last = self.last.load(Ordering::SeqCst);
+        let next = loop {
+            let candidate = std::cmp::max(now, last + 1);
+            match self.last.compare_exchange_weak(
+                last,
+                candidate,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => break candidate,
+                Err(actual) => last = actual,
+            }
+        };
+
+        if let Some(threshold) = self.warn_threshold {
+            let drift = next - now;
+            if drift > 0 && Duration::from_micros(drift as u64) > threshold {
+                log::warn!(
+                    "MonotonicTimestampGenerator: generated timestamp is {}µs ahead of the \
+                     system clock, which is more than the configured threshold of {:?}. The \
+                     clock may be lagging behind the rate at which timestamps are requested.",
+                    drift,
+                    threshold
+                );
+            }
+        }
+
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_timestamp_is_strictly_increasing_in_a_tight_loop() {
+        let generator = MonotonicTimestampGenerator::new();
+        let mut previous = generator.next_timestamp();
+        // Hammering the generator in a tight loop forces many calls to land within the same
+        // microsecond, which is exactly the case the CAS bump-by-1 logic exists to handle.
+        for _ in 0..10_000 {
+            let next = generator.next_timestamp();
+            assert!(next > previous, "{next} did not advance past {previous}");
+            previous = next;
+        }
+    }
+
+    #[test]
+    fn next_timestamp_is_shared_across_clones_via_the_same_instance() {
+        let generator = std::sync::Arc::new(MonotonicTimestampGenerator::new());
+        let clone = std::sync::Arc::clone(&generator);
+        let a = generator.next_timestamp();
+        let b = clone.next_timestamp();
+        assert!(b > a);
+    }
+
+    #[test]
+    fn drift_warning_threshold_does_not_panic_when_exceeded() {
+        // A zero threshold means any bump at all counts as drift; this should only trigger a
+        // log warning, never a panic or an incorrect value.
+        let generator = MonotonicTimestampGenerator::with_warn_threshold(Duration::from_micros(0));
+        let mut previous = generator.next_timestamp();
+        for _ in 0..1_000 {
+            let next = generator.next_timestamp();
+            assert!(next > previous);
+            previous = next;
+        }
+    }
+}
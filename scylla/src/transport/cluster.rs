@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use crate::routing::{Shard, Sharder, Token};
+
+/// A single node known to the driver.
+#[derive(Debug)]
+pub struct Node {
+    pub address: SocketAddr,
+    pub datacenter: Option<String>,
+    pub rack: Option<String>,
+
+    /// Sharding parameters reported by this node, if it's a ScyllaDB node. `None` for a plain
+    /// Cassandra node, which doesn't partition its data by shard.
+    pub sharder: Option<Sharder>,
+}
+
+impl Node {
+    /// The shard that owns `token` on this node, or `None` if the node has no `Sharder` (e.g. a
+    /// Cassandra node) or its `Sharder` reports zero shards.
+    pub fn shard_for_token(&self, token: Token) -> Option<Shard> {
+        self.sharder.as_ref().and_then(|sharder| sharder.shard_of(token))
+    }
+}
+
+/// A snapshot of the cluster's topology, as seen by the driver.
+#[derive(Debug, Default)]
+pub struct ClusterData {
+    pub known_peers: HashMap<SocketAddr, Arc<Node>>,
+}
+
+impl ClusterData {
+    pub fn all_nodes(&self) -> impl Iterator<Item = &Arc<Node>> {
+        self.known_peers.values()
+    }
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use crate::statement::TimestampGenerator;
+
+/// Shared, reusable configuration applied to statements that don't override it themselves.
+///
+/// Grouping configuration into a profile lets a whole family of statements share settings —
+/// including, e.g., a single [`TimestampGenerator`] so that client-side timestamps stay
+/// monotonic across the group rather than each statement computing its own.
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionProfile {
+    pub(crate) timestamp_generator: Option<Arc<dyn TimestampGenerator>>,
+}
+
+impl ExecutionProfile {
+    pub fn builder() -> ExecutionProfileBuilder {
+        ExecutionProfileBuilder::default()
+    }
+}
+
+/// A cheaply-cloneable handle to an [`ExecutionProfile`], as stored on a statement or session.
+#[derive(Debug, Clone)]
+pub struct ExecutionProfileHandle(Arc<ExecutionProfile>);
+
+impl ExecutionProfileHandle {
+    pub fn new(profile: ExecutionProfile) -> Self {
+        Self(Arc::new(profile))
+    }
+
+    /// The timestamp generator configured on this profile, if any.
+    pub fn timestamp_generator(&self) -> Option<Arc<dyn TimestampGenerator>> {
+        self.0.timestamp_generator.clone()
+    }
+}
+
+/// Builds an [`ExecutionProfile`].
+#[derive(Debug, Default)]
+pub struct ExecutionProfileBuilder {
+    timestamp_generator: Option<Arc<dyn TimestampGenerator>>,
+}
+
+impl ExecutionProfileBuilder {
+    pub fn timestamp_generator(mut self, generator: Arc<dyn TimestampGenerator>) -> Self {
+        self.timestamp_generator = Some(generator);
+        self
+    }
+
+    pub fn build(self) -> ExecutionProfile {
+        ExecutionProfile {
+            timestamp_generator: self.timestamp_generator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct FixedGenerator(i64);
+
+    impl TimestampGenerator for FixedGenerator {
+        fn next_timestamp(&self) -> i64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn handle_exposes_the_profiles_generator() {
+        let profile = ExecutionProfile::builder()
+            .timestamp_generator(Arc::new(FixedGenerator(7)))
+            .build();
+        let handle = ExecutionProfileHandle::new(profile);
+
+        assert_eq!(handle.timestamp_generator().unwrap().next_timestamp(), 7);
+    }
+
+    #[test]
+    fn handle_has_no_generator_by_default() {
+        let handle = ExecutionProfileHandle::new(ExecutionProfile::default());
+        assert!(handle.timestamp_generator().is_none());
+    }
+}
@@ -0,0 +1,272 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{ClusterData, FallbackPlan, LoadBalancingPolicy, NodeDistance, NodeRef, RoutingInfo};
+use crate::routing::Shard;
+use crate::transport::cluster::Node;
+use scylla_cql::errors::QueryError;
+
+/// The default, token- and datacenter-aware load balancing policy.
+///
+/// Nodes in the preferred datacenter (if any) are classified as [`NodeDistance::Local`] and
+/// tried first; others are [`NodeDistance::Remote`] (tried only as a fallback) or
+/// [`NodeDistance::Ignored`] (never contacted) depending on `permit_dc_failover`. When
+/// `is_token_aware` is set and the query carries a token, each node is additionally paired with
+/// the shard that actually owns the token on it (computed from the node's
+/// [`Sharder`](crate::routing::Sharder)), letting the connection pool avoid an extra cross-shard
+/// hop.
+#[derive(Debug, Clone)]
+pub struct DefaultPolicy {
+    pub(crate) preferred_datacenter: Option<String>,
+    pub(crate) permit_dc_failover: bool,
+    pub(crate) is_token_aware: bool,
+}
+
+impl DefaultPolicy {
+    pub fn builder() -> DefaultPolicyBuilder {
+        DefaultPolicyBuilder::default()
+    }
+
+    fn shard_for(&self, node: &Node, query: &RoutingInfo) -> Option<Shard> {
+        if !self.is_token_aware {
+            return None;
+        }
+        query.token.and_then(|token| node.shard_for_token(token))
+    }
+
+    /// Classifies `node` by datacenter: nodes in the preferred datacenter (or every node, if
+    /// none is configured) are `Local`; others are `Remote` when DC failover is permitted, or
+    /// `Ignored` (never contacted) otherwise.
+    fn distance_of(&self, node: &Node) -> NodeDistance {
+        let is_local = match &self.preferred_datacenter {
+            Some(preferred) => node.datacenter.as_deref() == Some(preferred.as_str()),
+            None => true,
+        };
+
+        if is_local {
+            NodeDistance::Local
+        } else if self.permit_dc_failover {
+            NodeDistance::Remote
+        } else {
+            NodeDistance::Ignored
+        }
+    }
+}
+
+impl LoadBalancingPolicy for DefaultPolicy {
+    fn pick<'a>(
+        &'a self,
+        query: &'a RoutingInfo,
+        cluster: &'a ClusterData,
+    ) -> Option<(NodeRef<'a>, Option<Shard>)> {
+        self.fallback(query, cluster).next()
+    }
+
+    fn fallback<'a>(&'a self, query: &'a RoutingInfo, cluster: &'a ClusterData) -> FallbackPlan<'a> {
+        let mut nodes: Vec<&'a Arc<Node>> = cluster
+            .all_nodes()
+            .filter(|node| self.distance_of(node) != NodeDistance::Ignored)
+            .collect();
+
+        // Local-datacenter nodes are tried before remote ones; this sort is stable, so within
+        // each group the order in which `all_nodes` yielded them (unspecified, as it iterates a
+        // `HashMap`) is preserved.
+        nodes.sort_by_key(|node| self.distance_of(node) != NodeDistance::Local);
+
+        Box::new(
+            nodes
+                .into_iter()
+                .map(move |node| (node, self.shard_for(node, query))),
+        )
+    }
+
+    fn on_query_success(&self, _query: &RoutingInfo, _latency: Duration, _node: NodeRef<'_>) {}
+
+    fn on_query_failure(
+        &self,
+        _query: &RoutingInfo,
+        _latency: Duration,
+        _node: NodeRef<'_>,
+        _error: &QueryError,
+    ) {
+    }
+
+    fn distance(&self, node: NodeRef<'_>, _cluster: &ClusterData) -> NodeDistance {
+        self.distance_of(node)
+    }
+
+    fn name(&self) -> String {
+        "DefaultPolicy".to_owned()
+    }
+}
+
+/// Builds a [`DefaultPolicy`].
+#[derive(Debug, Clone, Default)]
+pub struct DefaultPolicyBuilder {
+    preferred_datacenter: Option<String>,
+    permit_dc_failover: bool,
+    is_token_aware: bool,
+}
+
+impl DefaultPolicyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables token-aware routing: `pick`/`fallback` will compute the owning shard for nodes
+    /// that report sharding parameters.
+    pub fn token_aware(mut self, is_token_aware: bool) -> Self {
+        self.is_token_aware = is_token_aware;
+        self
+    }
+
+    pub fn prefer_datacenter(mut self, datacenter: String) -> Self {
+        self.preferred_datacenter = Some(datacenter);
+        self
+    }
+
+    pub fn permit_dc_failover(mut self, permit: bool) -> Self {
+        self.permit_dc_failover = permit;
+        self
+    }
+
+    pub fn build(self) -> DefaultPolicy {
+        DefaultPolicy {
+            preferred_datacenter: self.preferred_datacenter,
+            permit_dc_failover: self.permit_dc_failover,
+            is_token_aware: self.is_token_aware,
+        }
+    }
+}
+
+/// Holds an on/off setting for latency awareness (penalizing nodes whose recent latency is a
+/// statistical outlier compared to their peers). The builder only stores the flag: nothing in
+/// [`DefaultPolicyBuilder`] or [`DefaultPolicy`] reads it, and `DefaultPolicy` does not track
+/// or act on latency.
+#[derive(Debug, Clone, Default)]
+pub struct LatencyAwarenessBuilder {
+    enabled: bool,
+}
+
+impl LatencyAwarenessBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn enable(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::SocketAddr;
+
+    fn node(addr: &str, sharder: Option<crate::routing::Sharder>) -> Arc<Node> {
+        node_in_dc(addr, None, sharder)
+    }
+
+    fn node_in_dc(
+        addr: &str,
+        datacenter: Option<&str>,
+        sharder: Option<crate::routing::Sharder>,
+    ) -> Arc<Node> {
+        Arc::new(Node {
+            address: addr.parse::<SocketAddr>().unwrap(),
+            datacenter: datacenter.map(str::to_owned),
+            rack: None,
+            sharder,
+        })
+    }
+
+    fn cluster_with(nodes: Vec<Arc<Node>>) -> ClusterData {
+        ClusterData {
+            known_peers: nodes.into_iter().map(|n| (n.address, n)).collect(),
+        }
+    }
+
+    #[test]
+    fn fallback_computes_shard_for_token_aware_scylla_nodes() {
+        let scylla_node = node(
+            "127.0.0.1:9042",
+            Some(crate::routing::Sharder::new(4, 12)),
+        );
+        let cassandra_node = node("127.0.0.2:9042", None);
+        let cluster = cluster_with(vec![scylla_node.clone(), cassandra_node.clone()]);
+
+        let policy = DefaultPolicy::builder().token_aware(true).build();
+        let query = RoutingInfo {
+            token: Some(crate::routing::Token { value: 42 }),
+            ..Default::default()
+        };
+
+        let plan: Vec<_> = policy.fallback(&query, &cluster).collect();
+        let shard_of = |addr: SocketAddr| {
+            plan.iter()
+                .find(|(node, _)| node.address == addr)
+                .map(|(_, shard)| *shard)
+                .unwrap()
+        };
+
+        assert!(shard_of(scylla_node.address).is_some());
+        assert_eq!(shard_of(cassandra_node.address), None);
+    }
+
+    #[test]
+    fn fallback_does_not_compute_shard_when_not_token_aware() {
+        let scylla_node = node(
+            "127.0.0.1:9042",
+            Some(crate::routing::Sharder::new(4, 12)),
+        );
+        let cluster = cluster_with(vec![scylla_node]);
+
+        let policy = DefaultPolicy::builder().token_aware(false).build();
+        let query = RoutingInfo {
+            token: Some(crate::routing::Token { value: 42 }),
+            ..Default::default()
+        };
+
+        for (_, shard) in policy.fallback(&query, &cluster) {
+            assert_eq!(shard, None);
+        }
+    }
+
+    #[test]
+    fn distance_classifies_by_preferred_datacenter() {
+        let local = node_in_dc("127.0.0.1:9042", Some("dc1"), None);
+        let remote = node_in_dc("127.0.0.2:9042", Some("dc2"), None);
+        let cluster = cluster_with(vec![local.clone(), remote.clone()]);
+
+        let failover_policy = DefaultPolicy::builder()
+            .prefer_datacenter("dc1".to_owned())
+            .permit_dc_failover(true)
+            .build();
+        assert_eq!(failover_policy.distance(&local, &cluster), NodeDistance::Local);
+        assert_eq!(failover_policy.distance(&remote, &cluster), NodeDistance::Remote);
+
+        let no_failover_policy = DefaultPolicy::builder()
+            .prefer_datacenter("dc1".to_owned())
+            .permit_dc_failover(false)
+            .build();
+        assert_eq!(no_failover_policy.distance(&remote, &cluster), NodeDistance::Ignored);
+    }
+
+    #[test]
+    fn fallback_excludes_ignored_nodes_and_prefers_local_ones() {
+        let local = node_in_dc("127.0.0.1:9042", Some("dc1"), None);
+        let remote = node_in_dc("127.0.0.2:9042", Some("dc2"), None);
+        let cluster = cluster_with(vec![remote.clone(), local.clone()]);
+
+        let policy = DefaultPolicy::builder()
+            .prefer_datacenter("dc1".to_owned())
+            .permit_dc_failover(false)
+            .build();
+        let query = RoutingInfo::default();
+
+        let plan: Vec<_> = policy.fallback(&query, &cluster).collect();
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].0.address, local.address);
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use scylla_cql::errors::QueryError;
+
+use super::{ClusterData, FallbackPlan, LoadBalancingPolicy, NodeDistance, NodeRef, RoutingInfo};
+use crate::routing::Shard;
+
+/// A [`LoadBalancingPolicy`] wrapper that restricts the nodes produced by another policy to
+/// those accepted by a predicate.
+///
+/// This is useful for pinning traffic to a subset of nodes (e.g. an allow-list or a deny-list
+/// keyed by node address; [`Node`](crate::transport::cluster::Node) carries no host id to key
+/// by) during migrations or canary testing, without having to
+/// reimplement token or datacenter awareness: `FilteringPolicy` simply delegates to `inner` and
+/// filters out whatever the predicate rejects.
+#[derive(Clone)]
+pub struct FilteringPolicy {
+    inner: Arc<dyn LoadBalancingPolicy>,
+    predicate: Arc<dyn Fn(NodeRef) -> bool + Send + Sync>,
+}
+
+impl fmt::Debug for FilteringPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FilteringPolicy")
+            .field("inner", &self.inner)
+            .field("predicate", &"<predicate>")
+            .finish()
+    }
+}
+
+impl FilteringPolicy {
+    /// Creates a new `FilteringPolicy` which only ever produces nodes from `inner` that satisfy
+    /// `predicate`.
+    pub fn new(
+        inner: Arc<dyn LoadBalancingPolicy>,
+        predicate: Arc<dyn Fn(NodeRef) -> bool + Send + Sync>,
+    ) -> Self {
+        Self { inner, predicate }
+    }
+}
+
+impl LoadBalancingPolicy for FilteringPolicy {
+    fn pick<'a>(
+        &'a self,
+        query: &'a RoutingInfo,
+        cluster: &'a ClusterData,
+    ) -> Option<(NodeRef<'a>, Option<Shard>)> {
+        match self.inner.pick(query, cluster) {
+            Some((node, shard)) if (self.predicate)(node) => Some((node, shard)),
+            // The picked node is filtered out (or there was none to begin with): fall back to
+            // the first node of the (already filtered) fallback plan, if any.
+            _ => self.fallback(query, cluster).next(),
+        }
+    }
+
+    fn fallback<'a>(
+        &'a self,
+        query: &'a RoutingInfo,
+        cluster: &'a ClusterData,
+    ) -> FallbackPlan<'a> {
+        let predicate = Arc::clone(&self.predicate);
+        Box::new(
+            self.inner
+                .fallback(query, cluster)
+                .filter(move |(node, _shard)| predicate(node)),
+        )
+    }
+
+    fn on_query_success(&self, query: &RoutingInfo, latency: Duration, node: NodeRef<'_>) {
+        self.inner.on_query_success(query, latency, node)
+    }
+
+    fn on_query_failure(
+        &self,
+        query: &RoutingInfo,
+        latency: Duration,
+        node: NodeRef<'_>,
+        error: &QueryError,
+    ) {
+        self.inner.on_query_failure(query, latency, node, error)
+    }
+
+    fn distance(&self, node: NodeRef<'_>, cluster: &ClusterData) -> NodeDistance {
+        if (self.predicate)(node) {
+            self.inner.distance(node, cluster)
+        } else {
+            NodeDistance::Ignored
+        }
+    }
+
+    fn name(&self) -> String {
+        self.inner.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::cluster::{ClusterData, Node};
+    use std::net::SocketAddr;
+
+    #[derive(Debug)]
+    struct FixedPolicy {
+        nodes: Vec<Arc<Node>>,
+    }
+
+    impl LoadBalancingPolicy for FixedPolicy {
+        fn pick<'a>(
+            &'a self,
+            _query: &'a RoutingInfo,
+            _cluster: &'a ClusterData,
+        ) -> Option<(NodeRef<'a>, Option<Shard>)> {
+            self.nodes.first().map(|node| (node, None))
+        }
+
+        fn fallback<'a>(
+            &'a self,
+            _query: &'a RoutingInfo,
+            _cluster: &'a ClusterData,
+        ) -> FallbackPlan<'a> {
+            Box::new(self.nodes.iter().map(|node| (node, None)))
+        }
+
+        fn name(&self) -> String {
+            "FixedPolicy".to_owned()
+        }
+    }
+
+    fn node(addr: &str) -> Arc<Node> {
+        Arc::new(Node {
+            address: addr.parse::<SocketAddr>().unwrap(),
+            datacenter: None,
+            rack: None,
+            sharder: None,
+        })
+    }
+
+    fn allow_only(addr: SocketAddr) -> Arc<dyn Fn(NodeRef) -> bool + Send + Sync> {
+        Arc::new(move |node: NodeRef| node.address == addr)
+    }
+
+    #[test]
+    fn pick_falls_back_when_the_inner_pick_is_filtered_out() {
+        let denied = node("127.0.0.1:9042");
+        let allowed = node("127.0.0.2:9042");
+        let inner = Arc::new(FixedPolicy {
+            nodes: vec![denied, allowed.clone()],
+        });
+        let policy = FilteringPolicy::new(inner, allow_only(allowed.address));
+
+        let query = RoutingInfo::default();
+        let cluster = ClusterData::default();
+        let (picked, _) = policy.pick(&query, &cluster).expect("allowed node survives");
+        assert_eq!(picked.address, allowed.address);
+    }
+
+    #[test]
+    fn pick_returns_none_when_no_node_survives_filtering() {
+        let denied = node("127.0.0.1:9042");
+        let other_addr: SocketAddr = "127.0.0.2:9042".parse().unwrap();
+        let inner = Arc::new(FixedPolicy { nodes: vec![denied] });
+        let policy = FilteringPolicy::new(inner, allow_only(other_addr));
+
+        let query = RoutingInfo::default();
+        let cluster = ClusterData::default();
+        assert!(policy.pick(&query, &cluster).is_none());
+    }
+
+    #[test]
+    fn fallback_only_yields_nodes_matching_the_predicate() {
+        let denied = node("127.0.0.1:9042");
+        let allowed = node("127.0.0.2:9042");
+        let inner = Arc::new(FixedPolicy {
+            nodes: vec![denied, allowed.clone()],
+        });
+        let policy = FilteringPolicy::new(inner, allow_only(allowed.address));
+
+        let query = RoutingInfo::default();
+        let cluster = ClusterData::default();
+        let plan: Vec<_> = policy.fallback(&query, &cluster).collect();
+
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].0.address, allowed.address);
+    }
+
+    #[test]
+    fn distance_is_ignored_for_filtered_out_nodes() {
+        let denied = node("127.0.0.1:9042");
+        let allowed_addr: SocketAddr = "127.0.0.2:9042".parse().unwrap();
+        let inner = Arc::new(FixedPolicy {
+            nodes: vec![denied.clone()],
+        });
+        let policy = FilteringPolicy::new(inner, allow_only(allowed_addr));
+
+        let cluster = ClusterData::default();
+        assert_eq!(policy.distance(&denied, &cluster), NodeDistance::Ignored);
+    }
+}
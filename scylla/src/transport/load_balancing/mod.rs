@@ -3,14 +3,16 @@
 //! See [the book](https://rust-driver.docs.scylladb.com/stable/load-balancing/load-balancing.html) for more information
 
 use super::{cluster::ClusterData, NodeRef};
-use crate::routing::Token;
+use crate::routing::{Shard, Token};
 use scylla_cql::{errors::QueryError, frame::types};
 
 use std::time::Duration;
 
 mod default;
+mod filtering;
 mod plan;
 pub use default::{DefaultPolicy, DefaultPolicyBuilder, LatencyAwarenessBuilder};
+pub use filtering::FilteringPolicy;
 pub use plan::Plan;
 
 /// Represents info about statement that can be used by load balancing policies.
@@ -39,7 +41,11 @@ pub struct RoutingInfo<'a> {
 ///
 /// It is computed on-demand, only if querying the most preferred node fails
 /// (or when speculative execution is triggered).
-pub type FallbackPlan<'a> = Box<dyn Iterator<Item = NodeRef<'a>> + Send + Sync + 'a>;
+///
+/// Alongside each node, a target shard is returned whenever the policy is able to compute one
+/// (e.g. via token-aware routing against a Scylla node). `None` means that the query execution
+/// layer is free to pick any shard of that node, which is always the case for Cassandra nodes.
+pub type FallbackPlan<'a> = Box<dyn Iterator<Item = (NodeRef<'a>, Option<Shard>)> + Send + Sync + 'a>;
 
 /// Policy that decides which nodes to contact for each query.
 ///
@@ -61,11 +67,21 @@ pub type FallbackPlan<'a> = Box<dyn Iterator<Item = NodeRef<'a>> + Send + Sync +
 /// successfully, and there is no need to retry).
 ///
 /// This trait is used to produce an iterator of nodes to contact for a given query.
+///
+/// Besides the node, `pick` and `fallback` also return the shard that should be used to
+/// contact it, when the policy is able to compute one (see [`RoutingInfo::token`]). ScyllaDB
+/// runs a shard-per-core architecture, so knowing the target shard up front lets the
+/// connection pool hand the query to the per-shard connection that owns the token, avoiding a
+/// cross-shard hop inside the server. `None` means "any shard of this node will do".
 pub trait LoadBalancingPolicy: Send + Sync + std::fmt::Debug {
-    /// Returns the first node to contact for a given query.
-    fn pick<'a>(&'a self, query: &'a RoutingInfo, cluster: &'a ClusterData) -> Option<NodeRef<'a>>;
+    /// Returns the first node (and, if known, shard) to contact for a given query.
+    fn pick<'a>(
+        &'a self,
+        query: &'a RoutingInfo,
+        cluster: &'a ClusterData,
+    ) -> Option<(NodeRef<'a>, Option<Shard>)>;
 
-    /// Returns all contact-appropriate nodes for a given query.
+    /// Returns all contact-appropriate nodes (and, if known, their shards) for a given query.
     fn fallback<'a>(&'a self, query: &'a RoutingInfo, cluster: &'a ClusterData)
         -> FallbackPlan<'a>;
 
@@ -84,4 +100,28 @@ pub trait LoadBalancingPolicy: Send + Sync + std::fmt::Debug {
 
     /// Returns the name of load balancing policy.
     fn name(&self) -> String;
+
+    /// Classifies a node with respect to this policy, allowing the connection pool to decide
+    /// how many connections (if any) it is worth keeping open to it.
+    ///
+    /// The default implementation treats every node as [`NodeDistance::Local`], which preserves
+    /// the historical behaviour of opening a full pool to every node.
+    fn distance(&self, _node: NodeRef<'_>, _cluster: &ClusterData) -> NodeDistance {
+        NodeDistance::Local
+    }
+}
+
+/// Classification of a node with respect to a [`LoadBalancingPolicy`], used to decide how many
+/// connections the connection pool should keep open to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NodeDistance {
+    /// The node is a preferred contact point (e.g. it is in the local datacenter). The pool
+    /// should open its full, configured number of connections to it.
+    Local,
+    /// The node may be contacted, but only as a fallback (e.g. it is in a remote datacenter).
+    /// The pool should keep a reduced number of connections to it.
+    Remote,
+    /// The policy will never route to this node. The pool should not open any connections to
+    /// it at all.
+    Ignored,
 }
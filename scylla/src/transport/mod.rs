@@ -0,0 +1,9 @@
+pub mod cluster;
+pub mod execution_profile;
+pub mod load_balancing;
+
+pub use cluster::Node;
+
+/// A reference to a node known to the driver, as exposed to load balancing policies and the
+/// connection pool.
+pub type NodeRef<'a> = &'a std::sync::Arc<Node>;